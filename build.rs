@@ -0,0 +1,35 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+
+/// Deployed mainnet-beta store program id, used whenever `Cargo.toml` has no
+/// `[package.metadata.solana]` override.
+const DEFAULT_PROGRAM_ID: &str = "HEvSKofvBgfaexv23kMabbYqxasxU3mQ4ibBMEmJWHny";
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR not set");
+    let manifest_path = Path::new(&manifest_dir).join("Cargo.toml");
+    println!("cargo:rerun-if-changed={}", manifest_path.display());
+
+    let program_id = fs::read_to_string(&manifest_path)
+        .ok()
+        .and_then(|contents| contents.parse::<toml::Value>().ok())
+        .and_then(|manifest| {
+            manifest
+                .get("package")?
+                .get("metadata")?
+                .get("solana")?
+                .get("program-id")?
+                .as_str()
+                .map(str::to_owned)
+        })
+        .unwrap_or_else(|| DEFAULT_PROGRAM_ID.to_owned());
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR not set");
+    let dest_path = Path::new(&out_dir).join("program_id.rs");
+    fs::write(
+        &dest_path,
+        format!("solana_program::declare_id!(\"{program_id}\");\n"),
+    )
+    .expect("failed to write generated program_id.rs");
+}
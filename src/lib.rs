@@ -5,8 +5,10 @@
 
 extern crate borsh;
 use borsh::{BorshDeserialize, BorshSerialize};
+use fixed::types::I80F48;
 use solana_program::{
     account_info::AccountInfo,
+    clock::Clock,
     instruction::{AccountMeta, Instruction},
     program::invoke,
     program_error::ProgramError,
@@ -14,16 +16,71 @@ use solana_program::{
 };
 use std::result::Result;
 
-// The library uses this to verify the keys
-solana_program::declare_id!("HEvSKofvBgfaexv23kMabbYqxasxU3mQ4ibBMEmJWHny");
+mod oracle;
+pub use oracle::{OracleSource, PriceOracle};
 
+/// Errors returned by this crate's own checks, as opposed to errors
+/// surfaced by the underlying CPI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChainlinkError {
+    /// The round returned by the feed is older than the caller's configured
+    /// staleness bound.
+    StaleRound,
+    /// More feeds were passed to [`latest_round_data_many`] than
+    /// [`MAX_BATCH_FEEDS`] allows.
+    TooManyFeeds,
+}
+
+impl From<ChainlinkError> for ProgramError {
+    fn from(e: ChainlinkError) -> Self {
+        match e {
+            ChainlinkError::StaleRound => ProgramError::Custom(1),
+            ChainlinkError::TooManyFeeds => ProgramError::Custom(2),
+        }
+    }
+}
+
+/// Anchor-native mirror of [`ChainlinkError`], for use in the `_anchor`
+/// wrappers so callers get a distinct, named error instead of the generic
+/// `AccountNotEnoughKeys`.
+#[anchor_lang::error_code]
+pub enum ChainlinkAnchorError {
+    /// The round returned by the feed is older than the caller's configured
+    /// staleness bound.
+    #[msg("chainlink round data is stale")]
+    StaleRound,
+    /// More feeds were passed to `latest_round_data_many_anchor` than
+    /// [`MAX_BATCH_FEEDS`] allows.
+    #[msg("too many feeds passed to a single batch read")]
+    TooManyFeeds,
+}
+
+// The library uses this to verify the keys. Generated by `build.rs` from
+// `[package.metadata.solana] program-id` in Cargo.toml when present, falling
+// back to the deployed mainnet-beta id otherwise — see
+// `query_with_program_id` for retargeting a custom deployment at runtime
+// instead of at compile time.
+include!(concat!(env!("OUT_DIR"), "/program_id.rs"));
+
+/// Wire format for the store program's `Query` instruction. `pub` so
+/// advanced integrators can call [`query_with_program_id`] directly against
+/// a variant this crate doesn't expose a dedicated wrapper for.
 #[derive(BorshSerialize, BorshDeserialize)]
-enum Query {
+pub enum Query {
+    /// See [`version`].
     Version,
+    /// See [`decimals`].
     Decimals,
+    /// See [`description`].
     Description,
-    RoundData { round_id: u32 },
+    /// See [`round_data`].
+    RoundData {
+        /// The historical round to fetch.
+        round_id: u32,
+    },
+    /// See [`latest_round_data`].
     LatestRoundData,
+    /// See [`aggregator`].
     Aggregator,
 }
 
@@ -40,17 +97,220 @@ pub struct Round {
     pub answer: i128,
 }
 
+/// Zero-CPI account loader: validates an account's `owner` before
+/// Borsh-decoding its data directly, for callers that already hold the feed
+/// account and want to skip the CPI [`query`] path entirely.
+pub trait BorshState: BorshDeserialize + Sized {
+    /// Returns the program expected to own accounts of this type.
+    fn owner() -> Pubkey;
+
+    /// Validates `account.owner` and decodes its data with Borsh, returning
+    /// `InvalidAccountData` on an owner mismatch or malformed/short data.
+    /// Trailing bytes past what `Self` consumes are ignored rather than
+    /// treated as an error, since real accounts carry reserved/padding bytes
+    /// after their logical content.
+    fn load(account: &AccountInfo) -> Result<Self, ProgramError> {
+        if *account.owner != Self::owner() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let data = account.try_borrow_data()?;
+        if data.is_empty() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Self::deserialize(&mut &data[..]).map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
+
+/// Raw layout of the store program's transmissions account: a feed header
+/// followed by its latest transmission. Mirrors the same fields the CPI
+/// [`query`] path exposes via [`Round`] and [`decimals`], decoded directly
+/// from account data instead of through an `invoke` + `get_return_data`
+/// round trip.
+#[derive(BorshDeserialize)]
+struct TransmissionsAccount {
+    // Unread, but must stay in field order for Borsh to decode the bytes
+    // after them correctly.
+    _version: u8,
+    decimals: u8,
+    _description: String,
+    latest_round_id: u32,
+    latest_slot: u64,
+    latest_timestamp: u32,
+    latest_answer: i128,
+}
+
+impl BorshState for TransmissionsAccount {
+    fn owner() -> Pubkey {
+        ID
+    }
+}
+
+impl Round {
+    /// Decodes the latest round directly from a transmissions account's raw
+    /// data, with no CPI into the store program.
+    pub fn from_transmissions_account(ai: &AccountInfo) -> Result<Round, ProgramError> {
+        let account = TransmissionsAccount::load(ai)?;
+        Ok(Round {
+            round_id: account.latest_round_id,
+            slot: account.latest_slot,
+            timestamp: account.latest_timestamp,
+            answer: account.latest_answer,
+        })
+    }
+}
+
+/// Decodes a feed's `decimals` directly from its transmissions account, with
+/// no CPI into the store program.
+pub fn decimals_from_aggregator(ai: &AccountInfo) -> Result<u8, ProgramError> {
+    Ok(TransmissionsAccount::load(ai)?.decimals)
+}
+
+/// Lookup table of `10^(i - 12)` encoded as `I80F48`, indexed so that
+/// `DECIMAL_CONSTANTS[12]` is `10^0`. Used by [`Round::to_fixed`] to avoid a
+/// runtime `pow` on the hot path. Negative-exponent entries (index `< 12`)
+/// are rounded up to the nearest representable value rather than truncated,
+/// so scaling an answer never silently underestimates its magnitude.
+pub(crate) const DECIMAL_CONSTANTS: [I80F48; 25] = [
+    I80F48::from_bits(282),                           // 10^-12 (rounded up)
+    I80F48::from_bits(2815),                          // 10^-11 (rounded up)
+    I80F48::from_bits(28148),                         // 10^-10 (rounded up)
+    I80F48::from_bits(281475),                        // 10^-9 (rounded up)
+    I80F48::from_bits(2814750),                       // 10^-8 (rounded up)
+    I80F48::from_bits(28147498),                      // 10^-7 (rounded up)
+    I80F48::from_bits(281474977),                     // 10^-6 (rounded up)
+    I80F48::from_bits(2814749768),                    // 10^-5 (rounded up)
+    I80F48::from_bits(28147497672),                   // 10^-4 (rounded up)
+    I80F48::from_bits(281474976711),                  // 10^-3 (rounded up)
+    I80F48::from_bits(2814749767107),                 // 10^-2 (rounded up)
+    I80F48::from_bits(28147497671066),                // 10^-1 (rounded up)
+    I80F48::from_bits(281474976710656),               // 10^0
+    I80F48::from_bits(2814749767106560),              // 10^1
+    I80F48::from_bits(28147497671065600),             // 10^2
+    I80F48::from_bits(281474976710656000),            // 10^3
+    I80F48::from_bits(2814749767106560000),           // 10^4
+    I80F48::from_bits(28147497671065600000),          // 10^5
+    I80F48::from_bits(281474976710656000000),         // 10^6
+    I80F48::from_bits(2814749767106560000000),        // 10^7
+    I80F48::from_bits(28147497671065600000000),       // 10^8
+    I80F48::from_bits(281474976710656000000000),      // 10^9
+    I80F48::from_bits(2814749767106560000000000),     // 10^10
+    I80F48::from_bits(28147497671065600000000000),    // 10^11
+    I80F48::from_bits(281474976710656000000000000),   // 10^12
+];
+
+impl Round {
+    /// Returns `answer` decimal-adjusted by `decimals` as a fixed-point
+    /// `I80F48`, e.g. an answer of `123_456_789` with `decimals == 8` becomes
+    /// `1.23456789`.
+    ///
+    /// Looks up the scaling factor in [`DECIMAL_CONSTANTS`] instead of
+    /// computing `10^decimals` at runtime. Falls back to an explicit checked
+    /// `pow` for `decimals` outside the table's `[-12, 12]` exponent range,
+    /// failing with `ProgramError::InvalidArgument` instead of panicking if
+    /// even that overflows.
+    pub fn to_fixed(&self, decimals: u8) -> Result<I80F48, ProgramError> {
+        scale_to_fixed(self.answer, decimals)
+    }
+
+    /// Returns true if this round is more than `max_slots` behind
+    /// `current_slot`.
+    pub fn is_stale(&self, current_slot: u64, max_slots: u64) -> bool {
+        current_slot.saturating_sub(self.slot) > max_slots
+    }
+
+    /// Returns true if this round's oracle-reported `timestamp` is more than
+    /// `max_seconds` behind `current_timestamp`.
+    pub fn is_timestamp_stale(&self, current_timestamp: u32, max_seconds: u32) -> bool {
+        current_timestamp.saturating_sub(self.timestamp) > max_seconds
+    }
+}
+
+/// Scales a raw oracle answer by `10^-decimals`, shared by [`Round::to_fixed`]
+/// and the Switchboard [`crate::oracle::PriceOracle`] path. The Pyth path goes
+/// through [`scale_by_exponent`], which reads the same lookup table.
+///
+/// `decimals` is reported by the feed (or, via the zero-CPI path, decoded
+/// directly from account data), so an out-of-range value is treated as
+/// fallible input rather than an invariant to `expect()` on.
+pub(crate) fn scale_to_fixed(answer: i128, decimals: u8) -> Result<I80F48, ProgramError> {
+    let idx = 12 - decimals as i32;
+    if (0..25).contains(&idx) {
+        Ok(I80F48::from_num(answer) * DECIMAL_CONSTANTS[idx as usize])
+    } else {
+        let divisor = 10i128
+            .checked_pow(decimals as u32)
+            .ok_or(ProgramError::InvalidArgument)?;
+        let divisor = I80F48::checked_from_num(divisor).ok_or(ProgramError::InvalidArgument)?;
+        Ok(I80F48::from_num(answer) / divisor)
+    }
+}
+
+/// Scales a raw oracle answer by `10^exponent`, for providers (namely Pyth)
+/// whose reported exponent can be positive (multiply) as well as negative
+/// (divide), unlike Chainlink/Switchboard's always-non-negative `decimals`.
+///
+/// Reuses [`DECIMAL_CONSTANTS`] for in-range exponents the same way
+/// [`scale_to_fixed`] does, falling back to an explicit checked `pow` (and
+/// checked multiply, for positive exponents) outside the table's range.
+pub(crate) fn scale_by_exponent(answer: i128, exponent: i32) -> Result<I80F48, ProgramError> {
+    let idx = 12 + exponent;
+    if (0..25).contains(&idx) {
+        Ok(I80F48::from_num(answer) * DECIMAL_CONSTANTS[idx as usize])
+    } else if exponent <= 0 {
+        let decimals: u8 = (-exponent).try_into().map_err(|_| ProgramError::InvalidArgument)?;
+        let divisor = 10i128
+            .checked_pow(decimals as u32)
+            .ok_or(ProgramError::InvalidArgument)?;
+        Ok(I80F48::from_num(answer) / I80F48::from_num(divisor))
+    } else {
+        let multiplier = 10i128
+            .checked_pow(exponent as u32)
+            .ok_or(ProgramError::InvalidArgument)?;
+        let product = answer
+            .checked_mul(multiplier)
+            .ok_or(ProgramError::InvalidArgument)?;
+        Ok(I80F48::from_num(product))
+    }
+}
+
 fn query<'info, T: BorshDeserialize>(
     program_id: AccountInfo<'info>,
     feed: AccountInfo<'info>,
     scope: Query,
+) -> Result<T, ProgramError> {
+    if *program_id.key != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    invoke_query(program_id, feed, scope)
+}
+
+/// Like [`query`], but validates the CPI target against `program_id_key`
+/// instead of the compiled-in [`ID`]. Lets integrators on devnet/localnet,
+/// or running a forked deployment of the store program, retarget this crate
+/// at runtime without patching it.
+pub fn query_with_program_id<'info, T: BorshDeserialize>(
+    program_id_key: &Pubkey,
+    program_id: AccountInfo<'info>,
+    feed: AccountInfo<'info>,
+    scope: Query,
+) -> Result<T, ProgramError> {
+    if program_id.key != program_id_key {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    invoke_query(program_id, feed, scope)
+}
+
+fn invoke_query<'info, T: BorshDeserialize>(
+    program_id: AccountInfo<'info>,
+    feed: AccountInfo<'info>,
+    scope: Query,
 ) -> Result<T, ProgramError> {
     // Import std::io types explicitly to avoid conflicts with borsh::io
     use std::io::{Cursor, Write};
-    
+
     const QUERY_INSTRUCTION_DISCRIMINATOR: &[u8] =
         &[0x27, 0xfb, 0x82, 0x9f, 0x2e, 0x88, 0xa4, 0xa9];
-    
+
     // Avoid array resizes by using the maximum response size as the initial capacity.
     const MAX_SIZE: usize = QUERY_INSTRUCTION_DISCRIMINATOR.len() + std::mem::size_of::<Pubkey>();
     let mut data = Cursor::new(Vec::with_capacity(MAX_SIZE));
@@ -65,7 +325,7 @@ fn query<'info, T: BorshDeserialize>(
         data: data.into_inner(),
     };
 
-    invoke(&ix, &[feed.clone()])?;
+    invoke(&ix, std::slice::from_ref(&feed))?;
 
     let (_key, data) =
         solana_program::program::get_return_data().expect("chainlink store had no return_data!");
@@ -98,6 +358,15 @@ pub fn description<'info>(
     query(program_id, feed, Query::Description)
 }
 
+/// Returns round data for a specific historical round.
+pub fn round_data<'info>(
+    program_id: AccountInfo<'info>,
+    feed: AccountInfo<'info>,
+    round_id: u32,
+) -> Result<Round, ProgramError> {
+    query(program_id, feed, Query::RoundData { round_id })
+}
+
 /// Returns round data for the latest round.
 pub fn latest_round_data<'info>(
     program_id: AccountInfo<'info>,
@@ -106,6 +375,62 @@ pub fn latest_round_data<'info>(
     query(program_id, feed, Query::LatestRoundData)
 }
 
+/// Returns round data for the latest round, failing with
+/// [`ChainlinkError::StaleRound`] if it is more than `max_staleness_slots`
+/// behind `clock.slot`.
+pub fn latest_round_data_checked<'info>(
+    program_id: AccountInfo<'info>,
+    feed: AccountInfo<'info>,
+    max_staleness_slots: u64,
+    clock: &Clock,
+) -> Result<Round, ProgramError> {
+    let round = latest_round_data(program_id, feed)?;
+    if round.is_stale(clock.slot, max_staleness_slots) {
+        return Err(ChainlinkError::StaleRound.into());
+    }
+    Ok(round)
+}
+
+/// Maximum number of feeds [`latest_round_data_many`] will read in one call.
+///
+/// Each feed costs one CPI plus one locked account, so this keeps a caller
+/// well under the runtime's cap on accounts referenced by a single
+/// transaction, instead of failing partway through the batch.
+pub const MAX_BATCH_FEEDS: usize = 32;
+
+/// Returns [`latest_round_data`] for every feed in `feeds`, short-circuiting
+/// on the first error.
+///
+/// Fails fast with [`ChainlinkError::TooManyFeeds`] if `feeds.len()` exceeds
+/// [`MAX_BATCH_FEEDS`], rather than burning CPIs before hitting the
+/// runtime's own account limit mid-loop.
+pub fn latest_round_data_many<'info>(
+    program_id: &AccountInfo<'info>,
+    feeds: &[AccountInfo<'info>],
+) -> Result<Vec<Round>, ProgramError> {
+    if feeds.len() > MAX_BATCH_FEEDS {
+        return Err(ChainlinkError::TooManyFeeds.into());
+    }
+    feeds
+        .iter()
+        .map(|feed| latest_round_data(program_id.clone(), feed.clone()))
+        .collect()
+}
+
+/// Returns the latest round's answer, decimal-adjusted to an `I80F48`.
+///
+/// Equivalent to calling [`latest_round_data`] and [`decimals`] and feeding
+/// the results through [`Round::to_fixed`], but saves callers from having to
+/// wire up the second query themselves.
+pub fn scaled_answer<'info>(
+    program_id: AccountInfo<'info>,
+    feed: AccountInfo<'info>,
+) -> Result<I80F48, ProgramError> {
+    let round = latest_round_data(program_id.clone(), feed.clone())?;
+    let decimals = decimals(program_id, feed)?;
+    round.to_fixed(decimals)
+}
+
 /// Returns the address of the underlying OCR2 aggregator.
 pub fn aggregator<'info>(
     program_id: AccountInfo<'info>,
@@ -130,6 +455,19 @@ pub fn anchor_to_solana_account_info<'a, 'info: 'a>(
     }
 }
 
+/// Anchor-compatible wrapper for round_data
+pub fn round_data_anchor<'info>(
+    program_id: &anchor_lang::prelude::AccountInfo<'info>,
+    feed: &anchor_lang::prelude::AccountInfo<'info>,
+    round_id: u32,
+) -> anchor_lang::prelude::Result<Round> {
+    let program_info = anchor_to_solana_account_info(program_id);
+    let feed_info = anchor_to_solana_account_info(feed);
+
+    round_data(program_info, feed_info, round_id)
+        .map_err(|_| anchor_lang::error::Error::from(anchor_lang::error::ErrorCode::AccountNotEnoughKeys))
+}
+
 /// Anchor-compatible wrapper for latest_round_data
 pub fn latest_round_data_anchor<'info>(
     program_id: &anchor_lang::prelude::AccountInfo<'info>,
@@ -142,6 +480,42 @@ pub fn latest_round_data_anchor<'info>(
         .map_err(|_| anchor_lang::error::Error::from(anchor_lang::error::ErrorCode::AccountNotEnoughKeys))
 }
 
+/// Anchor-compatible wrapper for latest_round_data_many
+pub fn latest_round_data_many_anchor<'info>(
+    program_id: &anchor_lang::prelude::AccountInfo<'info>,
+    feeds: &[anchor_lang::prelude::AccountInfo<'info>],
+) -> anchor_lang::prelude::Result<Vec<Round>> {
+    let program_info = anchor_to_solana_account_info(program_id);
+    let feed_infos: Vec<_> = feeds.iter().map(anchor_to_solana_account_info).collect();
+
+    latest_round_data_many(&program_info, &feed_infos).map_err(|e| {
+        if e == ChainlinkError::TooManyFeeds.into() {
+            anchor_lang::error::Error::from(ChainlinkAnchorError::TooManyFeeds)
+        } else {
+            anchor_lang::error::Error::from(anchor_lang::error::ErrorCode::AccountNotEnoughKeys)
+        }
+    })
+}
+
+/// Anchor-compatible wrapper for latest_round_data_checked
+pub fn latest_round_data_checked_anchor<'info>(
+    program_id: &anchor_lang::prelude::AccountInfo<'info>,
+    feed: &anchor_lang::prelude::AccountInfo<'info>,
+    max_staleness_slots: u64,
+    clock: &Clock,
+) -> anchor_lang::prelude::Result<Round> {
+    let program_info = anchor_to_solana_account_info(program_id);
+    let feed_info = anchor_to_solana_account_info(feed);
+
+    latest_round_data_checked(program_info, feed_info, max_staleness_slots, clock).map_err(|e| {
+        if e == ChainlinkError::StaleRound.into() {
+            anchor_lang::error::Error::from(ChainlinkAnchorError::StaleRound)
+        } else {
+            anchor_lang::error::Error::from(anchor_lang::error::ErrorCode::AccountNotEnoughKeys)
+        }
+    })
+}
+
 /// Anchor-compatible wrapper for decimals
 pub fn decimals_anchor<'info>(
     program_id: &anchor_lang::prelude::AccountInfo<'info>,
@@ -164,4 +538,206 @@ pub fn description_anchor<'info>(
     
     description(program_info, feed_info)
         .map_err(|_| anchor_lang::error::Error::from(anchor_lang::error::ErrorCode::AccountNotEnoughKeys))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decimal_constants_match_independently_computed_ceiling() {
+        // Each entry is `ceil(10^(i - 12) * 2^48)`, checked against a
+        // reference computed without the lookup table, for a spread of
+        // common feed decimals plus the sub-unity range the original bug
+        // lived in.
+        for decimals in [0u8, 1, 2, 6, 8, 9, 12, 18] {
+            let idx = 12 - decimals as i32;
+            if decimals > 12 {
+                continue;
+            }
+            let num = 1u128 << 48;
+            let den = 10u128.pow(decimals as u32);
+            let expected_bits = num.div_ceil(den);
+            assert_eq!(
+                DECIMAL_CONSTANTS[idx as usize].to_bits() as u128,
+                expected_bits,
+                "decimals = {decimals}"
+            );
+        }
+    }
+
+    #[test]
+    fn scale_to_fixed_matches_plain_division_in_and_out_of_table() {
+        for decimals in [0u8, 1, 6, 8, 9, 18] {
+            let answer = 123_456_789i128;
+            let got = scale_to_fixed(answer, decimals).unwrap();
+            let want = I80F48::from_num(answer) / I80F48::from_num(10i128.pow(decimals as u32));
+            // The table rounds the per-unit scaling factor up by at most one
+            // ulp, so the answer it produces is off from plain division by at
+            // most `answer` ulps, never below it.
+            let tolerance = I80F48::DELTA.saturating_mul_int(answer.unsigned_abs() as i128);
+            assert!(
+                got >= want && got - want <= tolerance,
+                "decimals = {decimals}, got = {got}, want = {want}"
+            );
+        }
+    }
+
+    #[test]
+    fn scale_to_fixed_rejects_out_of_range_decimals_instead_of_panicking() {
+        assert_eq!(scale_to_fixed(1, 200), Err(ProgramError::InvalidArgument));
+    }
+
+    #[test]
+    fn scale_by_exponent_divides_for_negative_exponents() {
+        assert_eq!(
+            scale_by_exponent(123_456_789, -8).unwrap(),
+            scale_to_fixed(123_456_789, 8).unwrap()
+        );
+    }
+
+    #[test]
+    fn scale_by_exponent_multiplies_for_positive_exponents() {
+        // A positive `expo` means "multiply by 10^expo", per Pyth's own
+        // convention, not "divide" — the bug this test guards against.
+        let got = scale_by_exponent(42, 3).unwrap();
+        assert_eq!(got, I80F48::from_num(42_000));
+    }
+
+    #[test]
+    fn scale_by_exponent_rejects_overflowing_positive_exponent() {
+        assert_eq!(
+            scale_by_exponent(i128::MAX, 100),
+            Err(ProgramError::InvalidArgument)
+        );
+    }
+
+    fn round_with(slot: u64, timestamp: u32, answer: i128) -> Round {
+        Round {
+            round_id: 1,
+            slot,
+            timestamp,
+            answer,
+        }
+    }
+
+    #[test]
+    fn is_stale_true_only_past_the_bound() {
+        let round = round_with(100, 0, 0);
+        assert!(!round.is_stale(150, 50));
+        assert!(round.is_stale(151, 50));
+    }
+
+    #[test]
+    fn is_timestamp_stale_true_only_past_the_bound() {
+        let round = round_with(0, 100, 0);
+        assert!(!round.is_timestamp_stale(150, 50));
+        assert!(round.is_timestamp_stale(151, 50));
+    }
+
+    #[test]
+    fn max_batch_feeds_is_enforced() {
+        assert_eq!(MAX_BATCH_FEEDS, 32);
+        let program_key = Pubkey::new_unique();
+        let mut program_lamports = 0u64;
+        let mut program_data = [];
+        let program_info = AccountInfo::new(
+            &program_key,
+            false,
+            false,
+            &mut program_lamports,
+            &mut program_data,
+            &program_key,
+            false,
+            0,
+        );
+
+        let keys: Vec<Pubkey> = (0..MAX_BATCH_FEEDS + 1).map(|_| Pubkey::new_unique()).collect();
+        let mut lamports: Vec<u64> = vec![0; keys.len()];
+        let mut datas: Vec<[u8; 0]> = vec![[]; keys.len()];
+        let feeds: Vec<AccountInfo> = keys
+            .iter()
+            .zip(lamports.iter_mut())
+            .zip(datas.iter_mut())
+            .map(|((key, lamports), data)| {
+                AccountInfo::new(key, false, false, lamports, data, key, false, 0)
+            })
+            .collect();
+
+        let err: ProgramError = ChainlinkError::TooManyFeeds.into();
+        assert_eq!(latest_round_data_many(&program_info, &feeds).unwrap_err(), err);
+    }
+
+    /// Borsh-encodes the raw bytes of a [`TransmissionsAccount`], optionally
+    /// followed by `trailing` padding bytes, mirroring the layout real
+    /// accounts carry after their logical content.
+    fn encode_transmissions_account(
+        decimals: u8,
+        round_id: u32,
+        slot: u64,
+        timestamp: u32,
+        answer: i128,
+        trailing: usize,
+    ) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.push(1u8); // _version
+        data.push(decimals);
+        data.extend_from_slice(&0u32.to_le_bytes()); // empty _description
+        data.extend_from_slice(&round_id.to_le_bytes());
+        data.extend_from_slice(&slot.to_le_bytes());
+        data.extend_from_slice(&timestamp.to_le_bytes());
+        data.extend_from_slice(&answer.to_le_bytes());
+        data.extend(std::iter::repeat_n(0u8, trailing));
+        data
+    }
+
+    fn transmissions_account_info<'a>(
+        key: &'a Pubkey,
+        owner: &'a Pubkey,
+        lamports: &'a mut u64,
+        data: &'a mut [u8],
+    ) -> AccountInfo<'a> {
+        AccountInfo::new(key, false, true, lamports, data, owner, false, 0)
+    }
+
+    #[test]
+    fn transmissions_account_decodes_with_no_trailing_bytes() {
+        let key = Pubkey::new_unique();
+        let mut lamports = 0u64;
+        let mut data = encode_transmissions_account(8, 42, 100, 1_000, 123_456_789, 0);
+        let ai = transmissions_account_info(&key, &ID, &mut lamports, &mut data);
+
+        let round = Round::from_transmissions_account(&ai).unwrap();
+        assert_eq!(round.round_id, 42);
+        assert_eq!(round.slot, 100);
+        assert_eq!(round.timestamp, 1_000);
+        assert_eq!(round.answer, 123_456_789);
+        assert_eq!(decimals_from_aggregator(&ai).unwrap(), 8);
+    }
+
+    #[test]
+    fn transmissions_account_decodes_with_trailing_padding() {
+        let key = Pubkey::new_unique();
+        let mut lamports = 0u64;
+        let mut data = encode_transmissions_account(8, 42, 100, 1_000, 123_456_789, 16);
+        let ai = transmissions_account_info(&key, &ID, &mut lamports, &mut data);
+
+        let round = Round::from_transmissions_account(&ai).unwrap();
+        assert_eq!(round.answer, 123_456_789);
+        assert_eq!(decimals_from_aggregator(&ai).unwrap(), 8);
+    }
+
+    #[test]
+    fn transmissions_account_rejects_owner_mismatch() {
+        let key = Pubkey::new_unique();
+        let wrong_owner = Pubkey::new_unique();
+        let mut lamports = 0u64;
+        let mut data = encode_transmissions_account(8, 42, 100, 1_000, 123_456_789, 0);
+        let ai = transmissions_account_info(&key, &wrong_owner, &mut lamports, &mut data);
+
+        assert_eq!(
+            Round::from_transmissions_account(&ai).unwrap_err(),
+            ProgramError::InvalidAccountData
+        );
+    }
 }
\ No newline at end of file
@@ -0,0 +1,184 @@
+//! Unified price-oracle abstraction so a consuming program can accept a
+//! Chainlink, Pyth, or Switchboard V2 feed through a single code path.
+
+use crate::{decimals, latest_round_data, scale_by_exponent, scale_to_fixed};
+use fixed::types::I80F48;
+use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+/// Pyth's mainnet-beta oracle program id, used to detect Pyth price accounts
+/// by their `owner`.
+pub const PYTH_PROGRAM_ID: Pubkey =
+    solana_program::pubkey!("FsJ3A3u2vn5cTVofAjvy6y5kwABJAqYWpe4975bi2epH");
+
+/// Switchboard V2's mainnet-beta program id, used to detect Switchboard
+/// aggregator accounts by their `owner`.
+pub const SWITCHBOARD_V2_PROGRAM_ID: Pubkey =
+    solana_program::pubkey!("SW1TCH7qEPTdLsDHRgPuMQjbQxKdH2aBStViMFnt64f");
+
+/// Identifies which oracle provider backs a price feed account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OracleSource {
+    /// A Chainlink OCR2 feed, read via CPI into the Chainlink store program.
+    Chainlink,
+    /// A Pyth price account, decoded directly from account data.
+    Pyth,
+    /// A Switchboard V2 aggregator account, decoded directly from account
+    /// data.
+    SwitchboardV2,
+}
+
+impl OracleSource {
+    /// Infers the provider backing `ai` from its `owner` pubkey.
+    ///
+    /// Chainlink feeds are owned by this crate's store program
+    /// ([`crate::ID`]); Pyth and Switchboard are detected against their
+    /// well-known mainnet-beta program ids.
+    pub fn detect(ai: &AccountInfo) -> Result<Self, ProgramError> {
+        if *ai.owner == crate::ID {
+            Ok(OracleSource::Chainlink)
+        } else if *ai.owner == PYTH_PROGRAM_ID {
+            Ok(OracleSource::Pyth)
+        } else if *ai.owner == SWITCHBOARD_V2_PROGRAM_ID {
+            Ok(OracleSource::SwitchboardV2)
+        } else {
+            Err(ProgramError::IncorrectProgramId)
+        }
+    }
+}
+
+/// Normalizes a price feed of any supported [`OracleSource`] to a common
+/// `(price, decimals)` representation.
+pub trait PriceOracle {
+    /// Reads the current price and its decimal exponent from `feed`.
+    ///
+    /// `program_id` is only consulted for [`OracleSource::Chainlink`], where
+    /// it is the store program CPI'd into via [`latest_round_data`]; Pyth and
+    /// Switchboard are decoded directly from `feed`'s account data.
+    fn price<'info>(
+        &self,
+        program_id: AccountInfo<'info>,
+        feed: AccountInfo<'info>,
+    ) -> Result<(I80F48, u8), ProgramError>;
+}
+
+impl PriceOracle for OracleSource {
+    fn price<'info>(
+        &self,
+        program_id: AccountInfo<'info>,
+        feed: AccountInfo<'info>,
+    ) -> Result<(I80F48, u8), ProgramError> {
+        match self {
+            OracleSource::Chainlink => {
+                let decimals = decimals(program_id.clone(), feed.clone())?;
+                let round = latest_round_data(program_id, feed)?;
+                Ok((round.to_fixed(decimals)?, decimals))
+            }
+            OracleSource::Pyth => price_from_pyth(&feed),
+            OracleSource::SwitchboardV2 => price_from_switchboard(&feed),
+        }
+    }
+}
+
+fn price_from_pyth(ai: &AccountInfo) -> Result<(I80F48, u8), ProgramError> {
+    // `load_price_account`/`to_price_feed` only validate the account's magic
+    // number and internal layout, not who owns it, so a caller that skips
+    // `detect()` and calls this directly could otherwise be handed a
+    // well-formed blob planted by an account the attacker controls.
+    if *ai.owner != PYTH_PROGRAM_ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let data = ai.try_borrow_data()?;
+    let price_account: &pyth_sdk_solana::state::SolanaPriceAccount =
+        pyth_sdk_solana::state::load_price_account(&data)
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+    // `agg.status` is the feed's own trading-status flag; only `Trading`
+    // means the aggregate price below was actually produced by this update,
+    // so anything else (halted, in auction, stale-and-unknown) is treated as
+    // no price instead of silently returning a frozen last-seen value.
+    if price_account.agg.status != pyth_sdk_solana::state::PriceStatus::Trading {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let price = price_account.to_price_feed(ai.key).get_price_unchecked();
+    let decimals = price.expo.unsigned_abs() as u8;
+    // `expo` is signed: a feed can (rarely) report a positive exponent,
+    // meaning the raw price must be multiplied by `10^expo` rather than
+    // divided, so the sign has to be threaded through instead of collapsed
+    // by `unsigned_abs()` above.
+    Ok((scale_by_exponent(price.price as i128, price.expo)?, decimals))
+}
+
+fn price_from_switchboard(ai: &AccountInfo) -> Result<(I80F48, u8), ProgramError> {
+    // Same rationale as the owner check in `price_from_pyth` above.
+    if *ai.owner != SWITCHBOARD_V2_PROGRAM_ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    // `new_from_bytes` decodes from a raw slice instead of an `AccountInfo`,
+    // sidestepping the `AccountInfo` type mismatch between this crate's
+    // `solana-program` and the older one `switchboard-v2` itself depends on.
+    let data = ai.try_borrow_data()?;
+    let aggregator = switchboard_v2::AggregatorAccountData::new_from_bytes(&data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    let result = aggregator
+        .get_result()
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    let decimals = result.scale as u8;
+    Ok((scale_to_fixed(result.mantissa, decimals)?, decimals))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account_info<'a>(key: &'a Pubkey, owner: &'a Pubkey, data: &'a mut [u8]) -> AccountInfo<'a> {
+        static mut LAMPORTS: u64 = 0;
+        // `lamports` isn't read by either of these checks, so a single
+        // `static mut` sidesteps having to thread a fresh `&mut u64` through
+        // every call site.
+        AccountInfo::new(key, false, false, unsafe { &mut *std::ptr::addr_of_mut!(LAMPORTS) }, data, owner, false, 0)
+    }
+
+    #[test]
+    fn pyth_price_rejects_owner_mismatch_before_decoding() {
+        let key = Pubkey::new_unique();
+        let wrong_owner = Pubkey::new_unique();
+        let mut data = [];
+        let ai = account_info(&key, &wrong_owner, &mut data);
+        assert_eq!(price_from_pyth(&ai), Err(ProgramError::IncorrectProgramId));
+    }
+
+    #[test]
+    fn switchboard_price_rejects_owner_mismatch_before_decoding() {
+        let key = Pubkey::new_unique();
+        let wrong_owner = Pubkey::new_unique();
+        let mut data = [];
+        let ai = account_info(&key, &wrong_owner, &mut data);
+        assert_eq!(
+            price_from_switchboard(&ai),
+            Err(ProgramError::IncorrectProgramId)
+        );
+    }
+
+    #[test]
+    fn oracle_source_price_rejects_owner_mismatch_for_non_chainlink_variants() {
+        // `OracleSource` variants can be constructed directly without going
+        // through `detect()`, so `price()` itself must still reject an
+        // account whose owner doesn't match the claimed provider.
+        let program_key = Pubkey::new_unique();
+        let mut program_data = [];
+        let program_info = account_info(&program_key, &program_key, &mut program_data);
+
+        let feed_key = Pubkey::new_unique();
+        let wrong_owner = Pubkey::new_unique();
+        let mut feed_data = [];
+        let feed_info = account_info(&feed_key, &wrong_owner, &mut feed_data);
+
+        assert_eq!(
+            OracleSource::Pyth.price(program_info.clone(), feed_info.clone()),
+            Err(ProgramError::IncorrectProgramId)
+        );
+        assert_eq!(
+            OracleSource::SwitchboardV2.price(program_info, feed_info),
+            Err(ProgramError::IncorrectProgramId)
+        );
+    }
+}